@@ -147,6 +147,143 @@
 //! let system_set: SystemSet = graph.into();
 //! ```
 //!
+//! # Graph Introspection
+//! Once nodes are wired together, the graph can be queried for its structure without having
+//! to drain it into a [`SystemSet`] first: [`SystemGraph::dependencies`] and
+//! [`SystemGraph::dependents`] walk a single node's edges, [`SystemGraph::roots`] finds every
+//! node without dependencies, and [`SystemGraph::topological_order`] yields every node in an
+//! order that respects them all.
+//! ```rust
+//! # use bevy_system_graph::*;
+//! # use bevy_ecs::prelude::*;
+//! # fn sys_a() {}
+//! # fn sys_b() {}
+//! # fn sys_c() {}
+//! let graph = SystemGraph::new();
+//! let a = graph.root(sys_a);
+//! let b = a.then(sys_b);
+//! let c = a.then(sys_c);
+//!
+//! assert_eq!(graph.roots(), vec![a.id()]);
+//! assert_eq!(graph.dependents(a.id()).len(), 2);
+//! assert_eq!(graph.dependencies(b.id()), vec![a.id()]);
+//!
+//! // Nodes are always yielded after the nodes they depend on.
+//! let order: Vec<NodeId> = graph.topological_order().collect();
+//! assert_eq!(order[0], a.id());
+//! ```
+//!
+//! # Validating Graphs
+//! [`SystemGraph::validate`] (and the fallible [`TryFrom<SystemGraph>`] conversion) check
+//! that a graph has no cyclic dependency before Bevy ever sees it, instead of it panicking
+//! deep inside the scheduler's stage baking.
+//! ```rust
+//! # use bevy_system_graph::*;
+//! # use bevy_ecs::prelude::*;
+//! # use std::convert::TryFrom;
+//! # fn sys_a() {}
+//! # fn sys_b() {}
+//! let graph = SystemGraph::new();
+//! graph.root(sys_a).then(sys_b);
+//!
+//! // Validating an acyclic graph returns its nodes in a valid topological order.
+//! assert!(graph.validate().is_ok());
+//!
+//! // Converting via `TryFrom` validates for you; acyclic graphs convert just like `Into`.
+//! let system_set = SystemSet::try_from(graph);
+//! assert!(system_set.is_ok());
+//! ```
+//!
+//! # Reducing Redundant Edges
+//! Combining [fork] and [join] can produce dependency edges that are already implied by a
+//! longer path. [`SystemGraph::reduce`] drops them before the graph is baked, without
+//! changing the resulting execution order.
+//! ```rust
+//! # use bevy_system_graph::*;
+//! # use bevy_ecs::prelude::*;
+//! # fn sys_a() {}
+//! # fn sys_b() {}
+//! # fn sys_c() {}
+//! # fn sys_d() {}
+//! let graph = SystemGraph::new();
+//! let a = graph.root(sys_a);
+//! let (b, c) = a.fork((sys_b, sys_c));
+//! (b, c).join(sys_d);
+//!
+//! graph.reduce().expect("graph is a valid DAG");
+//!
+//! // Convert into a SystemSet
+//! let system_set: SystemSet = graph.into();
+//! ```
+//!
+//! # Visualizing with DOT
+//! Naming nodes via [`SystemGraphNode::with_name`] and exporting with
+//! [`SystemGraph::to_dot`] turns the graph into something you can actually look at.
+//! ```rust
+//! # use bevy_system_graph::*;
+//! # use bevy_ecs::prelude::*;
+//! # fn sys_a() {}
+//! # fn sys_b() {}
+//! let graph = SystemGraph::new();
+//! graph
+//!     .root(sys_a)
+//!     .with_name("Physics")
+//!     .then(sys_b)
+//!     .with_name("Render");
+//!
+//! let dot = graph.to_dot();
+//! assert!(dot.starts_with("digraph system_graph {"));
+//! assert!(dot.contains("Physics"));
+//! ```
+//!
+//! # Chaining Systems
+//! [`SystemGraph::chain`] and [`SystemGraphNode::then_chain`] wire a whole collection of
+//! systems into a straight line without hand-writing a [`then`] call for each one.
+//! ```rust
+//! # use bevy_system_graph::*;
+//! # use bevy_ecs::prelude::*;
+//! # fn sys_a() {}
+//! # fn sys_b() {}
+//! # fn sys_c() {}
+//! # fn sys_d() {}
+//! let graph = SystemGraph::new();
+//!
+//! // Equivalent to `graph.root(sys_a).then(sys_b).then(sys_c)`.
+//! let last = graph.chain(vec![sys_a as fn(), sys_b as fn(), sys_c as fn()]);
+//!
+//! // Keep going from the end of the chain.
+//! let last = last.then_chain(vec![sys_d as fn()]);
+//!
+//! // Convert into a SystemSet
+//! let system_set: SystemSet = graph.into();
+//! # let _ = last;
+//! ```
+//!
+//! [`then`]: SystemGraphNode::then
+//!
+//! # Labeling Graph Nodes
+//! A node keeps its place in the graph even after being given an additional label via
+//! [`SystemGraphNode::label`], so it can also participate in ordering constraints against
+//! systems outside the graph with [`SystemGraphNode::before`] and
+//! [`SystemGraphNode::after`].
+//! ```rust
+//! # use bevy_system_graph::*;
+//! # use bevy_ecs::prelude::*;
+//! # fn sys_a() {}
+//! # fn sys_b() {}
+//! let graph = SystemGraph::new();
+//! let root_a = graph
+//!     .root(sys_a)
+//!     .label("Physics")
+//!     .before("Propagate Transforms")
+//!     .after("Input");
+//!
+//! // `root_a` is still the same node, so the graph still sees it as the sole root.
+//! let b = root_a.then(sys_b);
+//! assert_eq!(graph.roots(), vec![root_a.id()]);
+//! assert_eq!(graph.dependents(root_a.id()), vec![b.id()]);
+//! ```
+//!
 //! # Cloning
 //! Individual [graph nodes] are backed by a [`Rc`], so cloning it will still
 //! point to the same logical underlying graph.
@@ -165,6 +302,7 @@ use bevy_ecs::schedule::{
 use bevy_utils::HashMap;
 use std::{
     cell::RefCell,
+    collections::{HashSet, VecDeque},
     fmt::Debug,
     rc::Rc,
     sync::atomic::{AtomicU32, Ordering},
@@ -172,6 +310,29 @@ use std::{
 
 static NEXT_GRAPH_ID: AtomicU32 = AtomicU32::new(0);
 
+/// Tracks the dependency edges of a [`SystemGraph`] in both directions so the graph can be
+/// queried without having to walk the baked [`SystemDescriptor`]s.
+#[derive(Default)]
+struct EdgeMap {
+    /// Maps an origin node to the nodes that directly depend on it.
+    forward: HashMap<NodeId, Vec<NodeId>>,
+    /// Maps a dependent node to the nodes it directly depends on.
+    reverse: HashMap<NodeId, Vec<NodeId>>,
+}
+
+impl EdgeMap {
+    fn add(&mut self, origin: NodeId, dependent: NodeId) {
+        self.forward
+            .entry(origin)
+            .or_insert_with(Vec::new)
+            .push(dependent);
+        self.reverse
+            .entry(dependent)
+            .or_insert_with(Vec::new)
+            .push(origin);
+    }
+}
+
 /// A builder for creating graphs of dependent parallel execution within a [`SystemStage`].
 ///
 /// Please see the crate level docs for examples on how to use this type.
@@ -184,6 +345,8 @@ static NEXT_GRAPH_ID: AtomicU32 = AtomicU32::new(0);
 pub struct SystemGraph {
     id: u32,
     nodes: Rc<RefCell<HashMap<NodeId, SystemDescriptor>>>,
+    edges: Rc<RefCell<EdgeMap>>,
+    names: Rc<RefCell<HashMap<NodeId, String>>>,
 }
 
 impl Default for SystemGraph {
@@ -191,6 +354,8 @@ impl Default for SystemGraph {
         Self {
             id: NEXT_GRAPH_ID.fetch_add(1, Ordering::Relaxed),
             nodes: Default::default(),
+            edges: Default::default(),
+            names: Default::default(),
         }
     }
 }
@@ -222,6 +387,33 @@ impl SystemGraph {
         self.id == other.id
     }
 
+    /// Wires each system in `systems` after the previous one, forming a linear dependency
+    /// chain that starts from a new root node, and returns the final [`SystemGraphNode`] so
+    /// it can be further [`fork`]ed or [`join`]ed.
+    ///
+    /// Equivalent to hand-calling `graph.root(a).then(b).then(c)...`, but far more ergonomic
+    /// for long pipelines.
+    ///
+    /// # Panics
+    /// Panics if `systems` is empty.
+    ///
+    /// [`fork`]: SystemGraphNode::fork
+    /// [`join`]: SystemJoin::join
+    pub fn chain<Params, T: IntoSystemDescriptor<Params>>(
+        &self,
+        systems: impl IntoIterator<Item = T>,
+    ) -> SystemGraphNode {
+        let mut systems = systems.into_iter();
+        let first = systems
+            .next()
+            .expect("Attempted to chain a collection of zero systems.");
+        let mut node = self.create_node(first.into_descriptor());
+        for system in systems {
+            node = node.then(system);
+        }
+        node
+    }
+
     fn create_node(&self, mut system: SystemDescriptor) -> SystemGraphNode {
         let mut nodes = self.nodes.borrow_mut();
         assert!(
@@ -245,26 +437,323 @@ impl SystemGraph {
         }
     }
 
-    fn add_dependency(&self, origin: NodeId, dependent: NodeId) {
+    fn map_node(&self, id: NodeId, f: impl FnOnce(SystemDescriptor) -> SystemDescriptor) {
         let mut nodes = self.nodes.borrow_mut();
-        if let Some(system) = nodes.remove(&dependent) {
-            nodes.insert(
-                dependent,
-                match system {
-                    SystemDescriptor::Parallel(descriptor) => {
-                        SystemDescriptor::Parallel(descriptor.after(origin))
-                    }
-                    SystemDescriptor::Exclusive(descriptor) => {
-                        SystemDescriptor::Exclusive(descriptor.after(origin))
-                    }
-                },
-            );
-        } else {
+        let system = nodes
+            .remove(&id)
+            .expect("Attempted to mutate a node that doesn't exist.");
+        nodes.insert(id, f(system));
+    }
+
+    fn add_dependency(&self, origin: NodeId, dependent: NodeId) {
+        if !self.nodes.borrow().contains_key(&dependent) {
             panic!(
                 "Attempted to add dependency for {:?}, which doesn't exist.",
                 dependent
             );
         }
+        self.edges.borrow_mut().add(origin, dependent);
+    }
+
+    /// Returns the ids of the nodes that `node` directly depends on.
+    ///
+    /// Returns an empty [`Vec`] if `node` has no dependencies or does not belong to this graph.
+    pub fn dependencies(&self, node: NodeId) -> Vec<NodeId> {
+        self.edges
+            .borrow()
+            .reverse
+            .get(&node)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns the ids of the nodes that directly depend on `node`.
+    ///
+    /// Returns an empty [`Vec`] if nothing depends on `node` or it does not belong to this
+    /// graph.
+    pub fn dependents(&self, node: NodeId) -> Vec<NodeId> {
+        self.edges
+            .borrow()
+            .forward
+            .get(&node)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns the ids of every root node in the graph: nodes with no dependencies.
+    pub fn roots(&self) -> Vec<NodeId> {
+        let edges = self.edges.borrow();
+        self.nodes
+            .borrow()
+            .keys()
+            .copied()
+            .filter(|id| edges.reverse.get(id).map_or(true, |deps| deps.is_empty()))
+            .collect()
+    }
+
+    /// Returns the ids of every node in the graph in a valid topological order: a node is
+    /// always yielded after all of the nodes it depends on.
+    ///
+    /// Nodes that do not depend on each other may be yielded in any relative order. Nodes
+    /// that are part of a cycle, or depend on one, are omitted; use [`SystemGraph::validate`]
+    /// to detect cycles.
+    pub fn topological_order(&self) -> impl Iterator<Item = NodeId> {
+        let nodes = self.nodes.borrow();
+        let edges = self.edges.borrow();
+        kahn_order(nodes.keys().copied(), &edges).0.into_iter()
+    }
+
+    /// Validates that the graph is a valid DAG (contains no cycles), returning the nodes in
+    /// a valid topological order on success.
+    ///
+    /// On failure, returns a [`GraphError::Cycle`] holding the path of one cycle. A cyclic
+    /// dependency would otherwise only surface as an opaque panic deep inside Bevy's stage
+    /// baking. Call this (or convert via [`TryFrom`]/[`TryInto`]) before baking the graph to
+    /// catch that earlier. `then`/`join`/`fork` always add edges towards a newly created node,
+    /// so they cannot form a cycle on their own.
+    pub fn validate(&self) -> Result<Vec<NodeId>, GraphError> {
+        let nodes = self.nodes.borrow();
+        let edges = self.edges.borrow();
+        let ids: Vec<NodeId> = nodes.keys().copied().collect();
+
+        let (order, _) = kahn_order(ids.iter().copied(), &edges);
+        if order.len() == ids.len() {
+            return Ok(order);
+        }
+
+        Err(GraphError::Cycle(find_cycle(&ids, &edges)))
+    }
+
+    /// Computes a transitive reduction over the graph's dependency edges, dropping edges that
+    /// are already implied by a longer path (e.g. drops `A -> C` if `A -> B -> C` also holds).
+    ///
+    /// Redundant edges like this add unnecessary work to Bevy's scheduler without changing the
+    /// resulting execution order, so removing them before baking shrinks the constraints it has
+    /// to satisfy. Validates the graph first and leaves it untouched if it is not a DAG.
+    pub fn reduce(&self) -> Result<(), GraphError> {
+        self.validate()?;
+
+        let mut edges = self.edges.borrow_mut();
+        let mut redundant = Vec::new();
+        for (&origin, dependents) in edges.forward.iter() {
+            for &dependent in dependents {
+                let implied = dependents
+                    .iter()
+                    .any(|&other| other != dependent && is_reachable(other, dependent, &edges));
+                if implied {
+                    redundant.push((origin, dependent));
+                }
+            }
+        }
+
+        for (origin, dependent) in redundant {
+            if let Some(dependents) = edges.forward.get_mut(&origin) {
+                dependents.retain(|&id| id != dependent);
+            }
+            if let Some(dependencies) = edges.reverse.get_mut(&dependent) {
+                dependencies.retain(|&id| id != origin);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Exports the graph to [Graphviz DOT] format for visualization.
+    ///
+    /// Nodes are labeled with the human-readable name given to them via
+    /// [`SystemGraphNode::with_name`], falling back to their [`NodeId`] otherwise. Root nodes
+    /// (no dependencies), fan-out nodes, and fan-in nodes are each given a distinct shape so
+    /// the graph's structure is visible at a glance.
+    ///
+    /// [Graphviz DOT]: https://graphviz.org/doc/info/lang.html
+    pub fn to_dot(&self) -> String {
+        let nodes = self.nodes.borrow();
+        let edges = self.edges.borrow();
+        let names = self.names.borrow();
+
+        let mut dot = String::from("digraph system_graph {\n");
+        for &id in nodes.keys() {
+            let label = names
+                .get(&id)
+                .cloned()
+                .unwrap_or_else(|| format!("{:?}", id));
+            let is_root = edges.reverse.get(&id).map_or(true, |deps| deps.is_empty());
+            let is_join = edges.reverse.get(&id).map_or(false, |deps| deps.len() > 1);
+            let is_fork = edges.forward.get(&id).map_or(false, |deps| deps.len() > 1);
+            let shape = if is_root {
+                "doublecircle"
+            } else if is_join {
+                "invtriangle"
+            } else if is_fork {
+                "triangle"
+            } else {
+                "ellipse"
+            };
+            dot.push_str(&format!(
+                "  \"{:?}\" [label=\"{}\", shape={}];\n",
+                id,
+                escape_dot_string(&label),
+                shape
+            ));
+        }
+        for (&origin, dependents) in edges.forward.iter() {
+            for &dependent in dependents {
+                dot.push_str(&format!("  \"{:?}\" -> \"{:?}\";\n", origin, dependent));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Escapes `"` and `\` so `s` can be safely interpolated into a double-quoted DOT string.
+fn escape_dot_string(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Returns whether `to` can be reached from `from` by following zero or more forward edges.
+fn is_reachable(from: NodeId, to: NodeId, edges: &EdgeMap) -> bool {
+    let mut seen = HashSet::new();
+    let mut stack = vec![from];
+    while let Some(node) = stack.pop() {
+        if node == to {
+            return true;
+        }
+        if !seen.insert(node) {
+            continue;
+        }
+        if let Some(dependents) = edges.forward.get(&node) {
+            stack.extend(dependents.iter().copied());
+        }
+    }
+    false
+}
+
+/// Runs Kahn's algorithm over `ids` and `edges`, returning the nodes reachable in topological
+/// order along with the in-degree map left over once no more zero in-degree nodes remain.
+/// If the remaining graph is acyclic, every id in `ids` appears in the returned order.
+fn kahn_order(
+    ids: impl Iterator<Item = NodeId>,
+    edges: &EdgeMap,
+) -> (Vec<NodeId>, HashMap<NodeId, usize>) {
+    let mut in_degree: HashMap<NodeId, usize> = ids.map(|id| (id, 0)).collect();
+    for dependents in edges.forward.values() {
+        for &dependent in dependents {
+            *in_degree.entry(dependent).or_insert(0) += 1;
+        }
+    }
+
+    let mut queue: VecDeque<NodeId> = in_degree
+        .iter()
+        .filter(|(_, &degree)| degree == 0)
+        .map(|(&id, _)| id)
+        .collect();
+
+    let mut order = Vec::with_capacity(in_degree.len());
+    while let Some(id) = queue.pop_front() {
+        order.push(id);
+        if let Some(dependents) = edges.forward.get(&id) {
+            for &dependent in dependents {
+                let degree = in_degree.get_mut(&dependent).unwrap();
+                *degree -= 1;
+                if *degree == 0 {
+                    queue.push_back(dependent);
+                }
+            }
+        }
+    }
+
+    (order, in_degree)
+}
+
+/// Recovers an actual cycle path (e.g. `A -> B -> C -> A`) via a DFS with a recursion stack,
+/// for use in [`GraphError::Cycle`] once [`kahn_order`] has determined a cycle exists.
+fn find_cycle(ids: &[NodeId], edges: &EdgeMap) -> Vec<NodeId> {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum Color {
+        Unvisited,
+        InProgress,
+        Done,
+    }
+
+    fn visit(
+        node: NodeId,
+        edges: &EdgeMap,
+        color: &mut HashMap<NodeId, Color>,
+        stack: &mut Vec<NodeId>,
+    ) -> Option<Vec<NodeId>> {
+        color.insert(node, Color::InProgress);
+        stack.push(node);
+        if let Some(dependents) = edges.forward.get(&node) {
+            for &next in dependents {
+                match color.get(&next).copied().unwrap_or(Color::Done) {
+                    Color::Unvisited => {
+                        if let Some(cycle) = visit(next, edges, color, stack) {
+                            return Some(cycle);
+                        }
+                    }
+                    Color::InProgress => {
+                        let start = stack.iter().position(|&id| id == next).unwrap();
+                        let mut cycle = stack[start..].to_vec();
+                        cycle.push(next);
+                        return Some(cycle);
+                    }
+                    Color::Done => {}
+                }
+            }
+        }
+        stack.pop();
+        color.insert(node, Color::Done);
+        None
+    }
+
+    let mut color: HashMap<NodeId, Color> = ids.iter().map(|&id| (id, Color::Unvisited)).collect();
+    let mut stack = Vec::new();
+    for &id in ids {
+        if color.get(&id) == Some(&Color::Unvisited) {
+            if let Some(cycle) = visit(id, edges, &mut color, &mut stack) {
+                return cycle;
+            }
+        }
+    }
+    Vec::new()
+}
+
+/// An error produced when a [`SystemGraph`] fails [`SystemGraph::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphError {
+    /// The graph contains a cycle. The path enumerates the offending [`NodeId`]s in order,
+    /// starting and ending on the same node (e.g. `[A, B, C, A]` for `A -> B -> C -> A`).
+    Cycle(Vec<NodeId>),
+}
+
+impl std::fmt::Display for GraphError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphError::Cycle(path) => {
+                write!(f, "cycle detected in SystemGraph: ")?;
+                for (i, id) in path.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " -> ")?;
+                    }
+                    write!(f, "{:?}", id)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for GraphError {}
+
+impl std::convert::TryFrom<SystemGraph> for SystemSet {
+    type Error = GraphError;
+
+    /// Validates the graph before draining it into a [`SystemSet`], failing with a
+    /// [`GraphError`] instead of producing a graph that would panic when Bevy bakes it.
+    fn try_from(graph: SystemGraph) -> Result<Self, Self::Error> {
+        graph.validate()?;
+        Ok(graph.into())
     }
 }
 
@@ -274,16 +763,25 @@ impl SystemGraph {
 /// [`SystemSet`]: bevy_ecs::schedule::SystemSet
 impl From<SystemGraph> for SystemSet {
     fn from(graph: SystemGraph) -> Self {
+        let edges = std::mem::take(&mut *graph.edges.borrow_mut());
         let mut system_set = SystemSet::new();
-        for (_, system) in graph.nodes.borrow_mut().drain() {
-            match system {
-                SystemDescriptor::Parallel(descriptor) => {
-                    system_set = system_set.with_system(descriptor);
-                }
-                SystemDescriptor::Exclusive(descriptor) => {
-                    system_set = system_set.with_system(descriptor);
+        for (id, mut system) in graph.nodes.borrow_mut().drain() {
+            if let Some(dependencies) = edges.reverse.get(&id) {
+                for &dependency in dependencies {
+                    system = match system {
+                        SystemDescriptor::Parallel(descriptor) => {
+                            SystemDescriptor::Parallel(descriptor.after(dependency))
+                        }
+                        SystemDescriptor::Exclusive(descriptor) => {
+                            SystemDescriptor::Exclusive(descriptor.after(dependency))
+                        }
+                    };
                 }
             }
+            system_set = match system {
+                SystemDescriptor::Parallel(descriptor) => system_set.with_system(descriptor),
+                SystemDescriptor::Exclusive(descriptor) => system_set.with_system(descriptor),
+            };
         }
         system_set
     }
@@ -309,6 +807,85 @@ impl SystemGraphNode {
         self.graph.clone()
     }
 
+    /// Gets the [`NodeId`] uniquely identifying this node within its [`SystemGraph`].
+    ///
+    /// This id can be used with [`SystemGraph::dependencies`], [`SystemGraph::dependents`],
+    /// and other graph-introspection queries.
+    #[inline]
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+
+    /// Assigns a human-readable name to this node, used in place of its [`NodeId`] when the
+    /// graph is exported via [`SystemGraph::to_dot`].
+    ///
+    /// Returns the node unchanged so this can be chained with [`then`], [`fork`], etc.
+    ///
+    /// [`then`]: SystemGraphNode::then
+    /// [`fork`]: SystemGraphNode::fork
+    #[inline]
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.graph.names.borrow_mut().insert(self.id, name.into());
+        self
+    }
+
+    /// Adds a [`SystemLabel`] to this node's underlying system, in addition to its
+    /// auto-assigned [`NodeId`] label.
+    ///
+    /// Lets the node participate in ordering constraints set up by systems outside the graph
+    /// via [`before`]/[`after`] without losing its place in the graph. Returns the node
+    /// unchanged so this can be chained with other builder calls.
+    ///
+    /// [`before`]: SystemGraphNode::before
+    /// [`after`]: SystemGraphNode::after
+    pub fn label(self, label: impl SystemLabel) -> Self {
+        self.graph.map_node(self.id, |system| match system {
+            SystemDescriptor::Parallel(descriptor) => {
+                SystemDescriptor::Parallel(descriptor.label(label))
+            }
+            SystemDescriptor::Exclusive(descriptor) => {
+                SystemDescriptor::Exclusive(descriptor.label(label))
+            }
+        });
+        self
+    }
+
+    /// Orders this node's system before the systems carrying the given [`SystemLabel`], even
+    /// if they lie outside the graph.
+    ///
+    /// Returns the node unchanged so this can be chained with other builder calls.
+    pub fn before(self, label: impl SystemLabel) -> Self {
+        self.graph.map_node(self.id, |system| match system {
+            SystemDescriptor::Parallel(descriptor) => {
+                SystemDescriptor::Parallel(descriptor.before(label))
+            }
+            SystemDescriptor::Exclusive(descriptor) => {
+                SystemDescriptor::Exclusive(descriptor.before(label))
+            }
+        });
+        self
+    }
+
+    /// Orders this node's system after the systems carrying the given [`SystemLabel`], even
+    /// if they lie outside the graph.
+    ///
+    /// This is independent of the graph's own dependency edges (see
+    /// [`SystemGraph::dependencies`]): labels added this way are not tracked by the graph's
+    /// introspection APIs, they only affect the baked [`SystemDescriptor`].
+    ///
+    /// Returns the node unchanged so this can be chained with other builder calls.
+    pub fn after(self, label: impl SystemLabel) -> Self {
+        self.graph.map_node(self.id, |system| match system {
+            SystemDescriptor::Parallel(descriptor) => {
+                SystemDescriptor::Parallel(descriptor.after(label))
+            }
+            SystemDescriptor::Exclusive(descriptor) => {
+                SystemDescriptor::Exclusive(descriptor.after(label))
+            }
+        });
+        self
+    }
+
     /// Creates a new node in the graph and adds the current node as its dependency.
     ///
     /// This function can be called multiple times to add mulitple systems to the graph,
@@ -319,6 +896,27 @@ impl SystemGraphNode {
         node
     }
 
+    /// Wires each system in `systems` after this node, and after each other in order, forming
+    /// a linear dependency chain starting from this node. Returns the final
+    /// [`SystemGraphNode`] so it can be further [`fork`]ed or [`join`]ed.
+    ///
+    /// Equivalent to hand-calling [`then`] for each element in turn. If `systems` is empty,
+    /// returns a node that refers to this same node.
+    ///
+    /// [`then`]: SystemGraphNode::then
+    /// [`fork`]: SystemGraphNode::fork
+    /// [`join`]: SystemJoin::join
+    pub fn then_chain<Params, T: IntoSystemDescriptor<Params>>(
+        &self,
+        systems: impl IntoIterator<Item = T>,
+    ) -> SystemGraphNode {
+        let mut node = self.clone();
+        for system in systems {
+            node = node.then(system);
+        }
+        node
+    }
+
     /// Fans out from the given node into multiple dependent systems. All provided
     /// systems will not run until the original node's system finishes running.
     ///
@@ -449,8 +1047,12 @@ impl_system_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14);
 impl_system_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15);
 impl_system_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16);
 
+/// Uniquely identifies a single node within a [`SystemGraph`].
+///
+/// The first field is the id of the owning [`SystemGraph`], and the second is the index of
+/// the node within that graph.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct NodeId(u32, u32);
+pub struct NodeId(u32, u32);
 
 impl SystemLabel for NodeId {
     fn dyn_clone(&self) -> Box<dyn SystemLabel> {
@@ -460,130 +1062,215 @@ impl SystemLabel for NodeId {
 
 #[cfg(test)]
 mod test {
-    // use super::*;
-    // use bevy_ecs::schedule::SystemDescriptor;
-
-    // fn dummy_system() {}
-
-    // fn assert_eq_after(sys: &SystemDescriptor, expected: Vec<NodeId>) {
-    //     let deps = match sys {
-    //         SystemDescriptor::Parallel(desc) => &desc.after,
-    //         SystemDescriptor::Exclusive(desc) => &desc.after,
-    //     };
-    //     let after: Vec<Box<dyn SystemLabel>> =
-    //         expected.into_iter().map(|id| id.dyn_clone()).collect();
-    //     assert_eq!(deps, &after);
-    // }
+    use super::*;
+    use std::collections::HashSet;
 
-    // #[test]
-    // pub fn then_creates_accurate_dependencies() {
-    //     let graph = SystemGraph::with_id(0);
-    //     graph
-    //         .root(dummy_system)
-    //         .then(dummy_system)
-    //         .then(dummy_system)
-    //         .then(dummy_system);
-
-    //     let systems = graph.nodes.borrow();
-
-    //     assert_eq!(systems.len(), 4);
-    //     assert_eq_after(&systems[&NodeId(0, 0)], vec![]);
-    //     assert_eq_after(&systems[&NodeId(0, 1)], vec![NodeId(0, 0)]);
-    //     assert_eq_after(&systems[&NodeId(0, 2)], vec![NodeId(0, 1)]);
-    //     assert_eq_after(&systems[&NodeId(0, 3)], vec![NodeId(0, 2)]);
-    // }
+    fn dummy_system() {}
 
-    // #[test]
-    // pub fn fork_creates_accurate_dependencies() {
-    //     let graph = SystemGraph::with_id(0);
-    //     graph
-    //         .root(dummy_system)
-    //         .fork((dummy_system, dummy_system, dummy_system));
+    /// Converts a collection of [`NodeId`] into a set, so collections can be compared ignoring
+    /// order.
+    fn to_set(ids: Vec<NodeId>) -> HashSet<(u32, u32)> {
+        ids.into_iter()
+            .map(|NodeId(graph, index)| (graph, index))
+            .collect()
+    }
 
-    //     let systems = graph.nodes.borrow();
+    #[test]
+    fn dependents_and_dependencies_reflect_then_and_join() {
+        let graph = SystemGraph::new();
+        let a = graph.root(dummy_system);
+        let b = a.then(dummy_system);
+        let c = a.then(dummy_system);
+        let d = (b.clone(), c.clone()).join(dummy_system);
 
-    //     assert_eq!(systems.len(), 4);
-    //     assert_eq_after(&systems[&NodeId(0, 0)], vec![]);
-    //     assert_eq_after(&systems[&NodeId(0, 1)], vec![NodeId(0, 0)]);
-    //     assert_eq_after(&systems[&NodeId(0, 2)], vec![NodeId(0, 0)]);
-    //     assert_eq_after(&systems[&NodeId(0, 3)], vec![NodeId(0, 0)]);
-    // }
+        assert_eq!(
+            to_set(graph.dependents(a.id())),
+            to_set(vec![b.id(), c.id()])
+        );
+        assert_eq!(
+            to_set(graph.dependencies(d.id())),
+            to_set(vec![b.id(), c.id()])
+        );
+        assert!(graph.dependencies(a.id()).is_empty());
+        assert!(graph.dependents(d.id()).is_empty());
+    }
 
-    // #[test]
-    // pub fn join_creates_accurate_dependencies() {
-    //     let graph = SystemGraph::with_id(0);
-    //     let a = graph.root(dummy_system);
-    //     let b = graph.root(dummy_system);
-    //     let c = graph.root(dummy_system);
-
-    //     (a, b, c).join(dummy_system);
-
-    //     let systems = graph.nodes.borrow();
-
-    //     assert_eq!(systems.len(), 4);
-    //     assert_eq_after(&systems[&NodeId(0, 0)], vec![]);
-    //     assert_eq_after(&systems[&NodeId(0, 1)], vec![]);
-    //     assert_eq_after(&systems[&NodeId(0, 2)], vec![]);
-    //     assert_eq_after(
-    //         &systems[&NodeId(0, 3)],
-    //         vec![NodeId(0, 0), NodeId(0, 1), NodeId(0, 2)],
-    //     );
-    // }
+    #[test]
+    fn roots_finds_only_nodes_without_dependencies() {
+        let graph = SystemGraph::new();
+        let a = graph.root(dummy_system);
+        let b = graph.root(dummy_system);
+        let _c = a.then(dummy_system);
 
-    // #[test]
-    // pub fn graph_creates_accurate_system_counts() {
-    //     let graph = SystemGraph::new();
-    //     let a = graph
-    //         .root(dummy_system)
-    //         .then(dummy_system)
-    //         .then(dummy_system)
-    //         .then(dummy_system);
-    //     let b = graph.root(dummy_system).then(dummy_system);
-    //     let c = graph
-    //         .root(dummy_system)
-    //         .then(dummy_system)
-    //         .then(dummy_system);
-    //     vec![a, b, c].join(dummy_system).then(dummy_system);
-    //     let system_set: SystemSet = graph.into();
-    //     let (_, systems) = system_set.bake();
-
-    //     assert_eq!(systems.len(), 11);
-    // }
+        assert_eq!(to_set(graph.roots()), to_set(vec![a.id(), b.id()]));
+    }
 
-    // #[test]
-    // pub fn all_nodes_are_labeled() {
-    //     let graph = SystemGraph::new();
-    //     let a = graph
-    //         .root(dummy_system)
-    //         .then(dummy_system)
-    //         .then(dummy_system)
-    //         .then(dummy_system);
-    //     let b = graph.root(dummy_system).then(dummy_system);
-    //     let c = graph
-    //         .root(dummy_system)
-    //         .then(dummy_system)
-    //         .then(dummy_system);
-    //     vec![a, b, c].join(dummy_system).then(dummy_system);
-    //     let system_set: SystemSet = graph.into();
-    //     let (_, systems) = system_set.bake();
-
-    //     let mut root_count = 0;
-    //     for system in systems {
-    //         match system {
-    //             SystemDescriptor::Parallel(desc) => {
-    //                 assert!(!desc.labels.is_empty());
-    //                 if desc.after.is_empty() {
-    //                     root_count += 1;
-    //                 }
-    //             }
-    //             SystemDescriptor::Exclusive(desc) => {
-    //                 assert!(!desc.labels.is_empty());
-    //                 if desc.after.is_empty() {
-    //                     root_count += 1;
-    //                 }
-    //             }
-    //         }
-    //     }
-    //     assert_eq!(root_count, 3);
-    // }
+    #[test]
+    fn topological_order_respects_dependencies() {
+        let graph = SystemGraph::new();
+        let a = graph.root(dummy_system);
+        let b = a.then(dummy_system);
+        let c = b.then(dummy_system);
+
+        let order: Vec<NodeId> = graph.topological_order().collect();
+        assert_eq!(order.len(), 3);
+        let pos = |id: NodeId| order.iter().position(|&n| n == id).unwrap();
+        assert!(pos(a.id()) < pos(b.id()));
+        assert!(pos(b.id()) < pos(c.id()));
+    }
+
+    #[test]
+    fn validate_returns_topological_order_for_acyclic_graph() {
+        let graph = SystemGraph::new();
+        let a = graph.root(dummy_system);
+        let b = a.then(dummy_system);
+        let c = b.then(dummy_system);
+
+        let order = graph.validate().expect("graph is acyclic");
+        let pos = |id: NodeId| order.iter().position(|&n| n == id).unwrap();
+        assert!(pos(a.id()) < pos(b.id()));
+        assert!(pos(b.id()) < pos(c.id()));
+    }
+
+    #[test]
+    fn validate_detects_cycle_and_reports_its_path() {
+        let graph = SystemGraph::new();
+        let a = graph.root(dummy_system);
+        let b = a.then(dummy_system);
+        let c = b.then(dummy_system);
+        // `then`/`join` can never create a cycle on their own (every dependent node is brand
+        // new), so wire one up directly through the crate-internal edge map, the way a future
+        // API misuse elsewhere in the crate could.
+        graph.add_dependency(c.id(), a.id());
+
+        match graph.validate() {
+            Err(GraphError::Cycle(path)) => {
+                assert_eq!(path.first(), path.last());
+                let cycle: HashSet<(u32, u32)> = to_set(path);
+                assert_eq!(cycle, to_set(vec![a.id(), b.id(), c.id()]));
+            }
+            Ok(_) => panic!("expected a cycle to be detected"),
+        }
+    }
+
+    #[test]
+    fn reduce_drops_edge_already_implied_by_a_longer_path() {
+        let graph = SystemGraph::new();
+        let a = graph.root(dummy_system);
+        let (b, c) = a.fork((dummy_system, dummy_system));
+        let d = (b.clone(), c.clone()).join(dummy_system);
+        // Redundant: `d` is already reachable from `a` via both `b` and `c`.
+        graph.add_dependency(a.id(), d.id());
+        assert_eq!(graph.dependencies(d.id()).len(), 3);
+
+        graph.reduce().expect("graph is a valid DAG");
+
+        assert_eq!(
+            to_set(graph.dependencies(d.id())),
+            to_set(vec![b.id(), c.id()])
+        );
+    }
+
+    #[test]
+    fn reduce_leaves_a_graph_with_no_redundant_edges_untouched() {
+        let graph = SystemGraph::new();
+        let a = graph.root(dummy_system);
+        let b = a.then(dummy_system);
+        let c = b.then(dummy_system);
+
+        graph.reduce().expect("graph is a valid DAG");
+
+        assert_eq!(to_set(graph.dependencies(b.id())), to_set(vec![a.id()]));
+        assert_eq!(to_set(graph.dependencies(c.id())), to_set(vec![b.id()]));
+    }
+
+    #[test]
+    fn reduce_fails_and_does_not_modify_a_cyclic_graph() {
+        let graph = SystemGraph::new();
+        let a = graph.root(dummy_system);
+        let b = a.then(dummy_system);
+        graph.add_dependency(b.id(), a.id());
+
+        let before = to_set(graph.dependencies(a.id()));
+        assert!(graph.reduce().is_err());
+        assert_eq!(to_set(graph.dependencies(a.id())), before);
+    }
+
+    #[test]
+    fn to_dot_escapes_quotes_and_backslashes_in_names() {
+        let graph = SystemGraph::new();
+        graph
+            .root(dummy_system)
+            .with_name("stage \"physics\" \\ render");
+
+        let dot = graph.to_dot();
+        assert!(dot.contains("stage \\\"physics\\\" \\\\ render"));
+        assert!(!dot.contains("stage \"physics\" \\ render"));
+    }
+
+    #[test]
+    fn chain_wires_systems_into_a_linear_dependency_chain() {
+        let graph = SystemGraph::new();
+        let last = graph.chain(vec![dummy_system, dummy_system, dummy_system]);
+
+        let order: Vec<NodeId> = graph.topological_order().collect();
+        assert_eq!(order.len(), 3);
+        assert_eq!(order.last().copied(), Some(last.id()));
+        assert_eq!(graph.roots().len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "zero systems")]
+    fn chain_panics_on_an_empty_collection() {
+        let graph = SystemGraph::new();
+        let _: SystemGraphNode = graph.chain(Vec::<fn()>::new());
+    }
+
+    #[test]
+    fn then_chain_extends_a_linear_chain_from_an_existing_node() {
+        let graph = SystemGraph::new();
+        let a = graph.root(dummy_system);
+        let last = a.then_chain(vec![dummy_system, dummy_system]);
+
+        let order: Vec<NodeId> = graph.topological_order().collect();
+        assert_eq!(order.len(), 3);
+        assert_eq!(order.first().copied(), Some(a.id()));
+        assert_eq!(order.last().copied(), Some(last.id()));
+    }
+
+    #[test]
+    fn then_chain_with_no_systems_returns_the_same_node() {
+        let graph = SystemGraph::new();
+        let a = graph.root(dummy_system);
+        let same = a.then_chain(Vec::<fn()>::new());
+
+        assert_eq!(same.id(), a.id());
+    }
+
+    #[test]
+    fn label_before_after_preserve_the_node_and_its_place_in_the_graph() {
+        let graph = SystemGraph::new();
+        let a = graph
+            .root(dummy_system)
+            .label("Physics")
+            .before("Propagate Transforms")
+            .after("Input");
+        let b = a.then(dummy_system);
+
+        assert_eq!(to_set(graph.roots()), to_set(vec![a.id()]));
+        assert_eq!(to_set(graph.dependents(a.id())), to_set(vec![b.id()]));
+    }
+
+    #[test]
+    fn label_before_after_do_not_add_graph_dependency_edges() {
+        let graph = SystemGraph::new();
+        let a = graph
+            .root(dummy_system)
+            .label("Physics")
+            .before("Render")
+            .after("Input");
+
+        assert!(graph.dependencies(a.id()).is_empty());
+        assert!(graph.dependents(a.id()).is_empty());
+    }
 }